@@ -22,9 +22,13 @@ pub type Sample = f32;
 pub const MAX_SAMPLES_PER_FRAME: usize = ffi::MINIMP3_MAX_SAMPLES_PER_FRAME as usize;
 
 /// Audio or miscellaneous data in a frame.
-pub enum Frame<'src, 'pcm> {
+///
+/// Generic over the PCM sample type `S`, which defaults to [`Sample`].
+/// [`Decoder::next_i16`]/[`Decoder::next_f32`] (and their `DecoderStream` counterparts)
+/// fix `S` to `i16`/`f32` so both output formats are available from the same build.
+pub enum Frame<'src, 'pcm, S = Sample> {
     /// PCM Sample Data
-    Audio(Samples<'src, 'pcm>),
+    Audio(Samples<'src, 'pcm, S>),
 
     /// Unknown Data
     Unknown {
@@ -45,12 +49,24 @@ pub struct Decoder(MaybeUninit<ffi::mp3dec_t>);
 #[repr(transparent)]
 pub struct DecoderBuf([Sample; MAX_SAMPLES_PER_FRAME]);
 
+/// Static buffer for holding 16-bit PCM data, used alongside [`Decoder::next_i16`]
+/// regardless of the `float` feature.
+#[repr(transparent)]
+pub struct DecoderBufI16([i16; MAX_SAMPLES_PER_FRAME]);
+
+/// Static buffer for holding 32-bit float PCM data, used alongside [`Decoder::next_f32`]
+/// regardless of the `float` feature.
+#[repr(transparent)]
+pub struct DecoderBufF32([f32; MAX_SAMPLES_PER_FRAME]);
+
 /// High-level streaming iterator for parsing or decoding MPEG Audio data.
 ///
 /// Potentially faster than a [`Decoder`] if planning to seek/decode entire data.
 pub struct DecoderStream<'src> {
     decoder: MaybeUninit<ffi::mp3dec_t>,
     decoder_buf: DecoderBuf,
+    decoder_buf_i16: DecoderBufI16,
+    decoder_buf_f32: DecoderBufF32,
     frame_recv: MaybeUninit<ffi::mp3dec_frame_info_t>,
     peek_cache_len: Option<usize>,
     source: &'src [u8],
@@ -63,10 +79,26 @@ pub struct DecoderStreamOwned {
     inner: DecoderStream<'static>,
 }
 
+/// High-level streaming decoder that pulls MPEG Audio data on demand from a [`Read`](std::io::Read)
+/// source, rather than requiring it all up front like [`DecoderStream`].
+///
+/// Internally keeps a buffer of roughly [`MAX_SAMPLES_PER_FRAME`] * 15 bytes, topping it up
+/// from `reader` whenever the unconsumed tail runs low.
+#[cfg(feature = "std")]
+pub struct DecoderReader<R> {
+    reader: R,
+    decoder: MaybeUninit<ffi::mp3dec_t>,
+    decoder_buf: DecoderBuf,
+    frame_recv: MaybeUninit<ffi::mp3dec_frame_info_t>,
+    buf: Box<[u8]>,
+    pos: usize,
+    len: usize,
+}
+
 /// PCM frame data yielded by a decoder.
 ///
 /// Note that if a `peek`ing function was used, [`samples`](Self::samples) will be empty.
-pub struct Samples<'src, 'pcm> {
+pub struct Samples<'src, 'pcm, S = Sample> {
     /// Bitrate of the source frame in kb/s.
     pub bitrate: u32,
     /// Number of channels in this frame.
@@ -84,7 +116,7 @@ pub struct Samples<'src, 'pcm> {
     /// Reference to the samples in this frame,
     /// contained in the output buffer.
     /// Empty if using [`peek`](Decoder::peek).
-    pub samples: &'pcm [Sample],
+    pub samples: &'pcm [S],
     /// Total sample count if using a `peek`ing function,
     /// since [`samples`](Samples::samples) would be empty.
     pub sample_count: usize,
@@ -93,6 +125,22 @@ pub struct Samples<'src, 'pcm> {
 /// Unit error type representing insufficient data in the input slice.
 pub struct InsufficientData;
 
+/// Error yielded by [`DecoderReader::next`].
+#[cfg(feature = "std")]
+pub enum ReaderError {
+    /// The underlying reader returned an error.
+    Io(std::io::Error),
+    /// Reached the end of the source without enough data left to decode another frame.
+    InsufficientData,
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for ReaderError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
 impl Decoder {
     /// Constructs a new `Decoder` for processing MPEG audio data.
     pub fn new() -> Self {
@@ -153,6 +201,118 @@ impl Decoder {
             })
         }
     }
+
+    /// Reads a frame without actually decoding it, identical to [`peek`](Self::peek)
+    /// since no samples are written either way.
+    #[inline(always)]
+    pub fn peek_i16<'src>(
+        &mut self,
+        data: &'src [u8],
+    ) -> Result<Frame<'src, 'static, i16>, InsufficientData> {
+        self.dec_i16(data, None)
+    }
+
+    /// Reads the next frame, decoding into 16-bit PCM regardless of the `float` feature.
+    #[inline(always)]
+    pub fn next_i16<'src, 'pcm>(
+        &mut self,
+        data: &'src [u8],
+        buf: &'pcm mut DecoderBufI16,
+    ) -> Result<Frame<'src, 'pcm, i16>, InsufficientData> {
+        self.dec_i16(data, Some(buf))
+    }
+
+    fn dec_i16<'src, 'pcm>(
+        &mut self,
+        data: &'src [u8],
+        buf: Option<&'pcm mut DecoderBufI16>,
+    ) -> Result<Frame<'src, 'pcm, i16>, InsufficientData> {
+        unsafe {
+            let mut frame_recv = MaybeUninit::uninit();
+            let data_len = data_len_safe(data.len());
+            let out_ptr = buf.map(|x| x.0.as_mut_ptr()).unwrap_or(ptr::null_mut());
+            let mut native = MaybeUninit::<[Sample; MAX_SAMPLES_PER_FRAME]>::uninit();
+            let native_ptr = if out_ptr.is_null() {
+                ptr::null_mut()
+            } else {
+                native.as_mut_ptr() as *mut Sample
+            };
+            let samples = ffi::mp3dec_decode_frame(
+                self.0.as_mut_ptr(),
+                data.as_ptr(),
+                data_len,
+                native_ptr,
+                frame_recv.as_mut_ptr(),
+            );
+            let frame_recv = &*frame_recv.as_ptr();
+            translate_response(frame_recv, samples, data, |pcm_points| {
+                if !out_ptr.is_null() {
+                    for i in 0..pcm_points {
+                        *out_ptr.add(i) = sample_to_i16(*native_ptr.add(i));
+                    }
+                    core::slice::from_raw_parts(out_ptr, pcm_points)
+                } else {
+                    &[]
+                }
+            })
+        }
+    }
+
+    /// Reads a frame without actually decoding it, identical to [`peek`](Self::peek)
+    /// since no samples are written either way.
+    #[inline(always)]
+    pub fn peek_f32<'src>(
+        &mut self,
+        data: &'src [u8],
+    ) -> Result<Frame<'src, 'static, f32>, InsufficientData> {
+        self.dec_f32(data, None)
+    }
+
+    /// Reads the next frame, decoding into 32-bit float PCM regardless of the `float` feature.
+    #[inline(always)]
+    pub fn next_f32<'src, 'pcm>(
+        &mut self,
+        data: &'src [u8],
+        buf: &'pcm mut DecoderBufF32,
+    ) -> Result<Frame<'src, 'pcm, f32>, InsufficientData> {
+        self.dec_f32(data, Some(buf))
+    }
+
+    fn dec_f32<'src, 'pcm>(
+        &mut self,
+        data: &'src [u8],
+        buf: Option<&'pcm mut DecoderBufF32>,
+    ) -> Result<Frame<'src, 'pcm, f32>, InsufficientData> {
+        unsafe {
+            let mut frame_recv = MaybeUninit::uninit();
+            let data_len = data_len_safe(data.len());
+            let out_ptr = buf.map(|x| x.0.as_mut_ptr()).unwrap_or(ptr::null_mut());
+            let mut native = MaybeUninit::<[Sample; MAX_SAMPLES_PER_FRAME]>::uninit();
+            let native_ptr = if out_ptr.is_null() {
+                ptr::null_mut()
+            } else {
+                native.as_mut_ptr() as *mut Sample
+            };
+            let samples = ffi::mp3dec_decode_frame(
+                self.0.as_mut_ptr(),
+                data.as_ptr(),
+                data_len,
+                native_ptr,
+                frame_recv.as_mut_ptr(),
+            );
+            let frame_recv = &*frame_recv.as_ptr();
+            translate_response(frame_recv, samples, data, |pcm_points| {
+                if !out_ptr.is_null() {
+                    for i in 0..pcm_points {
+                        *out_ptr.add(i) = sample_to_f32(*native_ptr.add(i));
+                    }
+                    core::slice::from_raw_parts(out_ptr, pcm_points)
+                } else {
+                    &[]
+                }
+            })
+        }
+    }
 }
 
 impl DecoderBuf {
@@ -162,6 +322,20 @@ impl DecoderBuf {
     }
 }
 
+impl DecoderBufI16 {
+    /// Constructs a new `DecoderBufI16`.
+    pub const fn new() -> Self {
+        Self([0i16; MAX_SAMPLES_PER_FRAME])
+    }
+}
+
+impl DecoderBufF32 {
+    /// Constructs a new `DecoderBufF32`.
+    pub const fn new() -> Self {
+        Self([0f32; MAX_SAMPLES_PER_FRAME])
+    }
+}
+
 impl<'src> DecoderStream<'src> {
     /// Constructs a new [`DecoderStream`] over the given MPEG audio bytes.
     pub fn new(source: &'src [u8]) -> Self {
@@ -172,6 +346,8 @@ impl<'src> DecoderStream<'src> {
                 decoder
             },
             decoder_buf: unsafe { MaybeUninit::uninit().assume_init() },
+            decoder_buf_i16: unsafe { MaybeUninit::uninit().assume_init() },
+            decoder_buf_f32: unsafe { MaybeUninit::uninit().assume_init() },
             frame_recv: MaybeUninit::uninit(),
             peek_cache_len: None,
             source,
@@ -228,6 +404,82 @@ impl<'src> DecoderStream<'src> {
         }
     }
 
+    /// Reads the next frame, decoding into 16-bit PCM regardless of the `float` feature.
+    pub fn next_i16<'pcm>(&'pcm mut self) -> Result<Frame<'src, 'pcm, i16>, InsufficientData> {
+        self.peek_cache_len = None;
+        unsafe {
+            let pcm_ptr = (&mut self.decoder_buf_i16) as *mut DecoderBufI16 as *mut i16;
+            let samples = self.dec_i16(pcm_ptr);
+            let frame_recv = &*self.frame_recv.as_ptr();
+            let response = translate_response(frame_recv, samples, &self.source, |points| {
+                (&*(pcm_ptr as *const [i16; MAX_SAMPLES_PER_FRAME])).get_unchecked(..points)
+            });
+
+            if response.is_ok() {
+                self.offset_trusted(frame_recv.frame_bytes as usize);
+            }
+
+            response
+        }
+    }
+
+    /// Reads a frame without actually decoding it or advancing the iterator,
+    /// identical to [`peek`](Self::peek) since no samples are written either way.
+    pub fn peek_i16(&mut self) -> Result<Frame<'src, 'static, i16>, InsufficientData> {
+        self.peek_cache_len = None;
+        unsafe {
+            let samples = self.dec_i16(ptr::null_mut());
+            let frame_recv = &*self.frame_recv.as_ptr();
+            let response = translate_response(frame_recv, samples, &self.source, |_| &[]);
+            match &response {
+                Ok(Frame::Audio(samples)) => self.peek_cache_len = Some(samples.bytes_consumed),
+                Ok(Frame::Unknown { bytes_consumed, .. }) => {
+                    self.peek_cache_len = Some(*bytes_consumed)
+                }
+                Err(_) => self.peek_cache_len = None,
+            }
+            response
+        }
+    }
+
+    /// Reads the next frame, decoding into 32-bit float PCM regardless of the `float` feature.
+    pub fn next_f32<'pcm>(&'pcm mut self) -> Result<Frame<'src, 'pcm, f32>, InsufficientData> {
+        self.peek_cache_len = None;
+        unsafe {
+            let pcm_ptr = (&mut self.decoder_buf_f32) as *mut DecoderBufF32 as *mut f32;
+            let samples = self.dec_f32(pcm_ptr);
+            let frame_recv = &*self.frame_recv.as_ptr();
+            let response = translate_response(frame_recv, samples, &self.source, |points| {
+                (&*(pcm_ptr as *const [f32; MAX_SAMPLES_PER_FRAME])).get_unchecked(..points)
+            });
+
+            if response.is_ok() {
+                self.offset_trusted(frame_recv.frame_bytes as usize);
+            }
+
+            response
+        }
+    }
+
+    /// Reads a frame without actually decoding it or advancing the iterator,
+    /// identical to [`peek`](Self::peek) since no samples are written either way.
+    pub fn peek_f32(&mut self) -> Result<Frame<'src, 'static, f32>, InsufficientData> {
+        self.peek_cache_len = None;
+        unsafe {
+            let samples = self.dec_f32(ptr::null_mut());
+            let frame_recv = &*self.frame_recv.as_ptr();
+            let response = translate_response(frame_recv, samples, &self.source, |_| &[]);
+            match &response {
+                Ok(Frame::Audio(samples)) => self.peek_cache_len = Some(samples.bytes_consumed),
+                Ok(Frame::Unknown { bytes_consumed, .. }) => {
+                    self.peek_cache_len = Some(*bytes_consumed)
+                }
+                Err(_) => self.peek_cache_len = None,
+            }
+            response
+        }
+    }
+
     /// Sets the current position in the input data.
     ///
     /// If `position` is out of bounds, it's set to the end of the file instead.
@@ -264,6 +516,62 @@ impl<'src> DecoderStream<'src> {
         )
     }
 
+    /// Decodes into `self.decoder_buf` (native [`Sample`] output), then converts into
+    /// `pcm_out`. Skips the decode entirely (header-only, like [`peek`](Self::peek)) if
+    /// `pcm_out` is null.
+    #[inline(always)]
+    unsafe fn dec_i16(&mut self, pcm_out: *mut i16) -> c_int {
+        let native_ptr = if pcm_out.is_null() {
+            ptr::null_mut()
+        } else {
+            (&mut self.decoder_buf) as *mut DecoderBuf as *mut Sample
+        };
+        let data_len = data_len_safe(self.source.len());
+        let samples = ffi::mp3dec_decode_frame(
+            self.decoder.as_mut_ptr(),
+            self.source.as_ptr(),
+            data_len,
+            native_ptr,
+            self.frame_recv.as_mut_ptr(),
+        );
+        if samples > 0 && !pcm_out.is_null() {
+            let frame_recv = &*self.frame_recv.as_ptr();
+            let count = samples as usize * frame_recv.channels as usize;
+            for i in 0..count {
+                *pcm_out.add(i) = sample_to_i16(*native_ptr.add(i));
+            }
+        }
+        samples
+    }
+
+    /// Decodes into `self.decoder_buf` (native [`Sample`] output), then converts into
+    /// `pcm_out`. Skips the decode entirely (header-only, like [`peek`](Self::peek)) if
+    /// `pcm_out` is null.
+    #[inline(always)]
+    unsafe fn dec_f32(&mut self, pcm_out: *mut f32) -> c_int {
+        let native_ptr = if pcm_out.is_null() {
+            ptr::null_mut()
+        } else {
+            (&mut self.decoder_buf) as *mut DecoderBuf as *mut Sample
+        };
+        let data_len = data_len_safe(self.source.len());
+        let samples = ffi::mp3dec_decode_frame(
+            self.decoder.as_mut_ptr(),
+            self.source.as_ptr(),
+            data_len,
+            native_ptr,
+            self.frame_recv.as_mut_ptr(),
+        );
+        if samples > 0 && !pcm_out.is_null() {
+            let frame_recv = &*self.frame_recv.as_ptr();
+            let count = samples as usize * frame_recv.channels as usize;
+            for i in 0..count {
+                *pcm_out.add(i) = sample_to_f32(*native_ptr.add(i));
+            }
+        }
+        samples
+    }
+
     #[inline(always)]
     unsafe fn offset_trusted(&mut self, offset: usize) {
         self.source = self.source.get_unchecked(offset..);
@@ -289,11 +597,593 @@ impl DecoderStreamOwned {
         self.inner.next()
     }
 
+    /// Reads a frame without actually decoding it, identical to [`peek`](Self::peek)
+    /// since no samples are written either way.
+    pub fn peek_i16<'src>(&'src mut self) -> Result<Frame<'src, 'static, i16>, InsufficientData> {
+        self.inner.peek_i16()
+    }
+
+    /// Reads the next frame, decoding into 16-bit PCM regardless of the `float` feature.
+    pub fn next_i16<'dec>(&'dec mut self) -> Result<Frame<'dec, 'dec, i16>, InsufficientData> {
+        self.inner.next_i16()
+    }
+
+    /// Reads a frame without actually decoding it, identical to [`peek`](Self::peek)
+    /// since no samples are written either way.
+    pub fn peek_f32<'src>(&'src mut self) -> Result<Frame<'src, 'static, f32>, InsufficientData> {
+        self.inner.peek_f32()
+    }
+
+    /// Reads the next frame, decoding into 32-bit float PCM regardless of the `float` feature.
+    pub fn next_f32<'dec>(&'dec mut self) -> Result<Frame<'dec, 'dec, f32>, InsufficientData> {
+        self.inner.next_f32()
+    }
+
     pub fn skip(&mut self) -> Result<(), InsufficientData> {
         self.inner.skip()
     }
 }
 
+/// Refill trigger: once the unconsumed tail drops below this many bytes, top up the buffer.
+#[cfg(feature = "std")]
+const READER_REFILL_TRIGGER: usize = MAX_SAMPLES_PER_FRAME * 8;
+
+/// Buffer capacity held by a [`DecoderReader`].
+#[cfg(feature = "std")]
+const READER_BUF_CAPACITY: usize = MAX_SAMPLES_PER_FRAME * 15;
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> DecoderReader<R> {
+    /// Constructs a new `DecoderReader`, pulling MPEG audio data from `reader` as needed.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            decoder: unsafe {
+                let mut decoder = MaybeUninit::<ffi::mp3dec_t>::uninit();
+                ffi::mp3dec_init(decoder.as_mut_ptr());
+                decoder
+            },
+            decoder_buf: unsafe { MaybeUninit::uninit().assume_init() },
+            frame_recv: MaybeUninit::uninit(),
+            buf: std::vec![0u8; READER_BUF_CAPACITY].into_boxed_slice(),
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Reads the next frame, skipping over garbage, returning a [`Frame`] if successful.
+    ///
+    /// Pulls more data from the underlying reader as needed. Returns
+    /// [`ReaderError::InsufficientData`] once the source is exhausted without enough
+    /// data left to decode (or skip over) another frame.
+    pub fn next<'dec>(&'dec mut self) -> Result<Frame<'dec, 'dec>, ReaderError> {
+        if self.len - self.pos < READER_REFILL_TRIGGER {
+            self.fill()?;
+        }
+
+        // Retry against `self` without tying any borrow to `'dec` until a frame (or
+        // garbage span) is actually ready, so the loop itself never conflicts with the
+        // `&mut self` that `fill` needs on the next pass.
+        let samples = loop {
+            let samples = self.decode_attempt();
+            let frame_bytes = unsafe { (&*self.frame_recv.as_ptr()).frame_bytes };
+            if samples != 0 || frame_bytes != 0 {
+                break samples;
+            }
+            if self.fill()? == 0 {
+                return Err(ReaderError::InsufficientData);
+            }
+        };
+
+        unsafe {
+            let pcm_ptr = (&mut self.decoder_buf) as *mut DecoderBuf as *mut Sample;
+            let frame_recv = &*self.frame_recv.as_ptr();
+            let window = self.buf.get_unchecked(self.pos..self.len);
+            match translate_response(frame_recv, samples, window, |points| {
+                (&*(pcm_ptr as *const [Sample; MAX_SAMPLES_PER_FRAME])).get_unchecked(..points)
+            }) {
+                Ok(frame) => {
+                    self.pos += frame_recv.frame_bytes as usize;
+                    Ok(frame)
+                }
+                Err(InsufficientData) => Err(ReaderError::InsufficientData),
+            }
+        }
+    }
+
+    /// Attempts to decode a frame out of the currently buffered window, returning the
+    /// raw sample count `mp3dec_decode_frame` reported (without constructing a
+    /// [`Frame`], so this carries no lifetime tied to the decoded output).
+    fn decode_attempt(&mut self) -> c_int {
+        unsafe {
+            let pcm_ptr = (&mut self.decoder_buf) as *mut DecoderBuf as *mut Sample;
+            let window = self.buf.get_unchecked(self.pos..self.len);
+            let data_len = data_len_safe(window.len());
+            ffi::mp3dec_decode_frame(
+                self.decoder.as_mut_ptr(),
+                window.as_ptr(),
+                data_len,
+                pcm_ptr,
+                self.frame_recv.as_mut_ptr(),
+            )
+        }
+    }
+
+    /// Moves the unconsumed tail to the front of the buffer and reads more data from
+    /// `reader` to top it up, looping over transient short reads. Returns the number
+    /// of bytes appended; `0` means the buffer was already full or `reader` is at EOF,
+    /// i.e. no further progress is possible without consuming what's already buffered.
+    fn fill(&mut self) -> Result<usize, std::io::Error> {
+        if self.pos > 0 {
+            self.buf.copy_within(self.pos..self.len, 0);
+            self.len -= self.pos;
+            self.pos = 0;
+        }
+
+        let mut appended = 0;
+        while self.len < self.buf.len() {
+            match self.reader.read(&mut self.buf[self.len..]) {
+                Ok(0) => break,
+                Ok(n) => {
+                    self.len += n;
+                    appended += n;
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(appended)
+    }
+}
+
+/// Converts decoded [`Samples`] from their source sample rate/channel count to a fixed
+/// target rate/channel count, e.g. to feed a playback sink that demands 48 kHz stereo
+/// regardless of the source MP3's native rate.
+///
+/// Carries the fractional interpolation phase (and the previous frame's last sample) across
+/// frame boundaries, so there are no clicks at frame joins. Call [`reset`](Self::reset)
+/// after seeking the underlying decoder, since the carried-over phase no longer applies.
+pub struct Resampler {
+    dst_rate: u32,
+    dst_channels: u32,
+    ratio: f64,
+    /// Fractional input-frame position, relative to the start of the next frame handed
+    /// to [`resample`](Self::resample)/[`resample_into`](Self::resample_into).
+    pos: f64,
+    /// Last (already channel-mixed) sample of the previous frame, per output channel.
+    /// Used as the sample at virtual index `-1` when `pos` carries over negative.
+    prev_tail: [Sample; 2],
+}
+
+impl Resampler {
+    /// Constructs a new `Resampler` converting from `src_rate`/`src_channels` to
+    /// `dst_rate`/`dst_channels`. Only mono and stereo channel counts are supported,
+    /// matching what MPEG audio itself can contain.
+    pub fn new(src_rate: u32, src_channels: u32, dst_rate: u32, dst_channels: u32) -> Self {
+        assert!(
+            src_channels <= 2 && dst_channels <= 2,
+            "Resampler only supports mono/stereo channel counts"
+        );
+        Self {
+            dst_rate,
+            dst_channels,
+            ratio: src_rate as f64 / dst_rate as f64,
+            pos: 0.0,
+            prev_tail: [0 as Sample; 2],
+        }
+    }
+
+    /// Sample rate samples are converted to.
+    pub fn dst_rate(&self) -> u32 {
+        self.dst_rate
+    }
+
+    /// Resets the carried-over interpolation phase. Call this after seeking, since
+    /// frames on either side of a seek are no longer contiguous.
+    pub fn reset(&mut self) {
+        self.pos = 0.0;
+        self.prev_tail = [0 as Sample; 2];
+    }
+
+    /// Resamples one decoded frame, returning the converted, interleaved samples.
+    #[cfg(feature = "std")]
+    pub fn resample(&mut self, samples: &Samples) -> std::vec::Vec<Sample> {
+        let mut out = std::vec::Vec::new();
+        self.resample_with(samples, |sample| out.push(sample));
+        out
+    }
+
+    /// Resamples one decoded frame into `out`, returning the number of samples written.
+    ///
+    /// `out` must be large enough to hold every sample this frame converts to; see
+    /// [`max_output_len`](Self::max_output_len) to size it ahead of time.
+    pub fn resample_into(&mut self, samples: &Samples, out: &mut [Sample]) -> usize {
+        let mut written = 0;
+        self.resample_with(samples, |sample| {
+            out[written] = sample;
+            written += 1;
+        });
+        written
+    }
+
+    /// Upper bound on how many samples [`resample_into`](Self::resample_into) can write
+    /// for a frame with `input_frames` samples per channel.
+    pub fn max_output_len(&self, input_frames: usize) -> usize {
+        let output_frames = ceil_f64(input_frames as f64 / self.ratio) as usize + 1;
+        output_frames * self.dst_channels as usize
+    }
+
+    fn resample_with(&mut self, samples: &Samples, mut push: impl FnMut(Sample)) {
+        let src_channels = samples.channels;
+        let input_frames = samples.sample_count;
+        if input_frames == 0 {
+            return;
+        }
+
+        while self.pos < input_frames as f64 - 1.0 {
+            let idx = floor_f64(self.pos);
+            let frac = self.pos - idx;
+            let idx = idx as isize;
+
+            for channel in 0..self.dst_channels as usize {
+                let lo = if idx < 0 {
+                    self.prev_tail[channel]
+                } else {
+                    mix_sample(samples.samples, src_channels, self.dst_channels, idx as usize, channel)
+                };
+                let hi = mix_sample(
+                    samples.samples,
+                    src_channels,
+                    self.dst_channels,
+                    (idx + 1) as usize,
+                    channel,
+                );
+                push(lerp(lo, hi, frac));
+            }
+
+            self.pos += self.ratio;
+        }
+
+        for channel in 0..self.dst_channels as usize {
+            self.prev_tail[channel] = mix_sample(
+                samples.samples,
+                src_channels,
+                self.dst_channels,
+                input_frames - 1,
+                channel,
+            );
+        }
+        self.pos -= input_frames as f64;
+    }
+}
+
+/// Reads one sample of frame `data` (interleaved, `src_channels` channels per frame) as
+/// if it already had `dst_channels`, duplicating mono to stereo or averaging stereo
+/// down to mono as needed.
+fn mix_sample(
+    data: &[Sample],
+    src_channels: u32,
+    dst_channels: u32,
+    frame_index: usize,
+    channel: usize,
+) -> Sample {
+    match (src_channels, dst_channels) {
+        (1, _) => data[frame_index],
+        (2, 1) => {
+            let base = frame_index * 2;
+            average(data[base], data[base + 1])
+        }
+        (src, _) => data[frame_index * src as usize + channel],
+    }
+}
+
+#[cfg(not(feature = "float"))]
+fn average(a: Sample, b: Sample) -> Sample {
+    ((a as i32 + b as i32) / 2) as i16
+}
+#[cfg(feature = "float")]
+fn average(a: Sample, b: Sample) -> Sample {
+    (a + b) * 0.5
+}
+
+#[cfg(not(feature = "float"))]
+fn lerp(a: Sample, b: Sample, t: f64) -> Sample {
+    round_f64(a as f64 + (b as f64 - a as f64) * t) as i16
+}
+#[cfg(feature = "float")]
+fn lerp(a: Sample, b: Sample, t: f64) -> Sample {
+    a + (b - a) * t as f32
+}
+
+// `core::f64`/`core::f32` have no `floor`/`ceil`/`round` (those live behind `std` or a
+// `libm` dependency), but `Resampler` needs to keep working under `no_std`, and the
+// magnitudes involved here (sample positions within a single decoded frame) comfortably
+// fit in an `i64`/`i32`, so a cast-and-compare round-trip is all that's needed.
+fn floor_f64(x: f64) -> f64 {
+    let truncated = x as i64 as f64;
+    if x < truncated {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+fn ceil_f64(x: f64) -> f64 {
+    let truncated = x as i64 as f64;
+    if x > truncated {
+        truncated + 1.0
+    } else {
+        truncated
+    }
+}
+
+fn round_f64(x: f64) -> f64 {
+    if x >= 0.0 {
+        floor_f64(x + 0.5)
+    } else {
+        ceil_f64(x - 0.5)
+    }
+}
+
+fn round_f32(x: f32) -> f32 {
+    if x >= 0.0 {
+        (x + 0.5) as i32 as f32
+    } else {
+        (x - 0.5) as i32 as f32
+    }
+}
+
+/// Converts one native [`Sample`] to `i16`, regardless of the `float` feature.
+#[cfg(not(feature = "float"))]
+fn sample_to_i16(s: Sample) -> i16 {
+    s
+}
+#[cfg(feature = "float")]
+fn sample_to_i16(s: Sample) -> i16 {
+    round_f32(s.clamp(-1.0, 1.0) * 32768.0) as i16
+}
+
+/// Converts one native [`Sample`] to `f32`, regardless of the `float` feature.
+#[cfg(not(feature = "float"))]
+fn sample_to_f32(s: Sample) -> f32 {
+    s as f32 / 32768.0
+}
+#[cfg(feature = "float")]
+fn sample_to_f32(s: Sample) -> f32 {
+    s
+}
+
+impl<'src, 'pcm> AsRef<[Sample]> for Samples<'src, 'pcm> {
+    fn as_ref(&self) -> &[Sample] {
+        self.samples
+    }
+}
+
+/// Ring-buffer bridge between a decoder and an audio callback that needs an exact
+/// number of interleaved samples per call, regardless of how the source happened to
+/// chunk them up (e.g. 576/1152 samples per decoded frame).
+#[cfg(feature = "std")]
+pub struct PcmQueue {
+    chunks: std::collections::VecDeque<std::boxed::Box<[Sample]>>,
+    /// Consumer cursor into the oldest (front) chunk.
+    head_cursor: usize,
+    /// Total unconsumed samples across every buffered chunk.
+    len: usize,
+}
+
+#[cfg(feature = "std")]
+impl PcmQueue {
+    /// Constructs a new, empty `PcmQueue`.
+    pub fn new() -> Self {
+        Self {
+            chunks: std::collections::VecDeque::new(),
+            head_cursor: 0,
+            len: 0,
+        }
+    }
+
+    /// Feeds more samples into the queue, e.g. `samples.samples` from a decoded
+    /// [`Frame::Audio`], or a raw `&[Sample]`.
+    pub fn push(&mut self, samples: impl AsRef<[Sample]>) {
+        let samples = samples.as_ref();
+        if samples.is_empty() {
+            return;
+        }
+        self.chunks.push_back(samples.into());
+        self.len += samples.len();
+    }
+
+    /// Total samples currently buffered and not yet consumed.
+    pub fn samples_available(&self) -> usize {
+        self.len
+    }
+
+    /// Copies exactly `out.len()` samples out of the queue, discarding fully-drained
+    /// chunks as it goes. Returns `false` (without consuming anything) if fewer than
+    /// `out.len()` samples are currently buffered.
+    pub fn consume_exact(&mut self, out: &mut [Sample]) -> bool {
+        if out.len() > self.len {
+            return false;
+        }
+
+        let mut written = 0;
+        while written < out.len() {
+            let chunk = &self.chunks[0];
+            let take = (chunk.len() - self.head_cursor).min(out.len() - written);
+            out[written..written + take]
+                .copy_from_slice(&chunk[self.head_cursor..self.head_cursor + take]);
+            written += take;
+            self.head_cursor += take;
+
+            if self.head_cursor == chunk.len() {
+                self.chunks.pop_front();
+                self.head_cursor = 0;
+            }
+        }
+
+        self.len -= out.len();
+        true
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for PcmQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A single indexed frame: the decoded state *before* this frame, plus where to find it.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy)]
+struct IndexEntry {
+    /// Byte offset of this frame in the stream's source data.
+    byte_offset: usize,
+    /// Sample rate of this frame in Hz.
+    sample_rate: u32,
+    /// Number of (per-channel) samples this frame decodes to.
+    sample_count: usize,
+    /// Samples decoded across every prior indexed frame.
+    samples_before: u64,
+    /// Duration, in seconds, of every prior indexed frame.
+    duration_before: f64,
+}
+
+/// Lazily-built time/sample seek index over a [`DecoderStream`], so seeking to a
+/// wall-clock time or sample offset in a VBR file doesn't require re-scanning from
+/// the caller's side.
+///
+/// Only the portion of the stream actually needed to satisfy a seek is scanned; to
+/// index (and pay for) the entire file up front, use [`duration`](Self::duration).
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub struct SeekIndex {
+    entries: std::vec::Vec<IndexEntry>,
+    /// Byte offset indexing has covered so far.
+    indexed_bytes: usize,
+    /// Set once indexing has reached the end of the stream.
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl SeekIndex {
+    /// Constructs a new, empty `SeekIndex`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seeks `stream` to the frame containing sample `target`, indexing further into
+    /// the stream as needed. Returns `false` (seeking to the last known frame instead)
+    /// if `target` is beyond the end of the stream.
+    pub fn seek_to_sample(&mut self, stream: &mut DecoderStream, target: u64) -> bool {
+        loop {
+            if let Some(entry) = self.find_by_sample(target) {
+                stream.set_position(entry.byte_offset);
+                return true;
+            }
+            if !self.grow(stream) {
+                if let Some(last) = self.entries.last() {
+                    stream.set_position(last.byte_offset);
+                }
+                return false;
+            }
+        }
+    }
+
+    /// Seeks `stream` to the frame containing wall-clock time `secs`, indexing further
+    /// into the stream as needed. Returns `false` (seeking to the last known frame
+    /// instead) if `secs` is beyond the end of the stream.
+    pub fn seek_to_duration(&mut self, stream: &mut DecoderStream, secs: f64) -> bool {
+        loop {
+            if let Some(entry) = self.find_by_duration(secs) {
+                stream.set_position(entry.byte_offset);
+                return true;
+            }
+            if !self.grow(stream) {
+                if let Some(last) = self.entries.last() {
+                    stream.set_position(last.byte_offset);
+                }
+                return false;
+            }
+        }
+    }
+
+    /// Fully indexes `stream` (if not already done) and returns its total duration in
+    /// seconds. Unlike [`seek_to_sample`](Self::seek_to_sample)/
+    /// [`seek_to_duration`](Self::seek_to_duration), this always scans to the end.
+    pub fn duration(&mut self, stream: &mut DecoderStream) -> f64 {
+        while self.grow(stream) {}
+        self.entries
+            .last()
+            .map(|e| e.duration_before + e.sample_count as f64 / e.sample_rate as f64)
+            .unwrap_or(0.0)
+    }
+
+    fn find_by_sample(&self, target: u64) -> Option<&IndexEntry> {
+        let i = self.entries.partition_point(|e| e.samples_before <= target);
+        let entry = i.checked_sub(1).map(|i| &self.entries[i])?;
+        (target < entry.samples_before + entry.sample_count as u64).then_some(entry)
+    }
+
+    fn find_by_duration(&self, secs: f64) -> Option<&IndexEntry> {
+        let i = self.entries.partition_point(|e| e.duration_before <= secs);
+        let entry = i.checked_sub(1).map(|i| &self.entries[i])?;
+        (secs < entry.duration_before + entry.sample_count as f64 / entry.sample_rate as f64)
+            .then_some(entry)
+    }
+
+    /// Indexes exactly one more frame (or the Xing/Info/garbage data preceding it),
+    /// leaving `stream`'s position as it found it. Returns `false` once the stream
+    /// is exhausted.
+    fn grow(&mut self, stream: &mut DecoderStream) -> bool {
+        if self.done {
+            return false;
+        }
+
+        let caller_position = stream.position();
+        stream.set_position(self.indexed_bytes);
+
+        let response = stream.peek();
+        match response {
+            Ok(Frame::Audio(samples)) => {
+                let (samples_before, duration_before) = self
+                    .entries
+                    .last()
+                    .map(|e| {
+                        (
+                            e.samples_before + e.sample_count as u64,
+                            e.duration_before + e.sample_count as f64 / e.sample_rate as f64,
+                        )
+                    })
+                    .unwrap_or((0, 0.0));
+                self.entries.push(IndexEntry {
+                    byte_offset: self.indexed_bytes,
+                    sample_rate: samples.sample_rate,
+                    sample_count: samples.sample_count,
+                    samples_before,
+                    duration_before,
+                });
+                let _ = stream.skip();
+                self.indexed_bytes = stream.position();
+                stream.set_position(caller_position);
+                true
+            }
+            Ok(Frame::Unknown { .. }) => {
+                // Xing/Info header or garbage: skip without an index entry.
+                let _ = stream.skip();
+                self.indexed_bytes = stream.position();
+                stream.set_position(caller_position);
+                true
+            }
+            Err(InsufficientData) => {
+                self.done = true;
+                stream.set_position(caller_position);
+                false
+            }
+        }
+    }
+}
+
 // The minimp3 API takes `int` for size, however that won't work if
 // your file exceeds 2GB (usually 2^31-1 bytes) in size. Thankfully,
 // under pretty much no circumstances will each frame be >2GB.
@@ -304,12 +1194,12 @@ unsafe fn data_len_safe(len: usize) -> c_int {
 }
 
 #[inline(always)]
-unsafe fn translate_response<'src, 'pcm>(
+unsafe fn translate_response<'src, 'pcm, S>(
     frame_recv: &ffi::mp3dec_frame_info_t,
     samples: c_int,
     source: &'src [u8],
-    pcm_f: impl Fn(usize) -> &'pcm [Sample],
-) -> Result<Frame<'src, 'pcm>, InsufficientData> {
+    pcm_f: impl Fn(usize) -> &'pcm [S],
+) -> Result<Frame<'src, 'pcm, S>, InsufficientData> {
     if samples != 0 {
         // we got samples!
         Ok(Frame::Audio(Samples {
@@ -343,3 +1233,147 @@ unsafe fn source_slice<'src, 'frame>(
 ) -> &'src [u8] {
     data.get_unchecked(frame_recv.frame_offset as usize..frame_recv.frame_bytes as usize)
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decoder_reader_next_reports_insufficient_data_on_empty_input() {
+        let mut reader = DecoderReader::new(std::io::Cursor::new(std::vec::Vec::new()));
+        assert!(matches!(reader.next(), Err(ReaderError::InsufficientData)));
+    }
+
+    #[test]
+    fn decoder_i16_f32_accessors_report_insufficient_data_on_empty_input() {
+        let mut decoder = Decoder::new();
+        assert!(matches!(decoder.peek_i16(&[]), Err(InsufficientData)));
+        assert!(matches!(decoder.peek_f32(&[]), Err(InsufficientData)));
+
+        let mut buf_i16 = DecoderBufI16::new();
+        assert!(matches!(decoder.next_i16(&[], &mut buf_i16), Err(InsufficientData)));
+        let mut buf_f32 = DecoderBufF32::new();
+        assert!(matches!(decoder.next_f32(&[], &mut buf_f32), Err(InsufficientData)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "float"))]
+    fn sample_conversions_match_i16_native_type() {
+        assert_eq!(sample_to_i16(1234), 1234);
+        assert_eq!(sample_to_f32(16384), 0.5);
+        assert_eq!(sample_to_f32(-16384), -0.5);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn sample_conversions_match_f32_native_type() {
+        assert_eq!(sample_to_f32(0.5), 0.5);
+        assert_eq!(sample_to_i16(1.0), i16::MAX);
+        assert_eq!(sample_to_i16(-1.0), i16::MIN);
+    }
+
+    #[test]
+    fn resample_frame_boundary_does_not_panic() {
+        let mut resampler = Resampler::new(44100, 1, 48000, 1);
+
+        let frame1: std::vec::Vec<Sample> = (0..1152).map(|i| i as Sample).collect();
+        let samples1 = Samples {
+            bitrate: 128,
+            channels: 1,
+            mpeg_layer: 3,
+            sample_rate: 44100,
+            bytes_consumed: 0,
+            source: &[],
+            samples: &frame1,
+            sample_count: frame1.len(),
+        };
+        assert!(!resampler.resample(&samples1).is_empty());
+
+        // Regressed to a negative-index underflow past `prev_tail`'s single sample of
+        // lookback the first time `pos` carried over negative into this second frame.
+        let frame2: std::vec::Vec<Sample> = (0..1152).map(|i| (1152 + i) as Sample).collect();
+        let samples2 = Samples {
+            bitrate: 128,
+            channels: 1,
+            mpeg_layer: 3,
+            sample_rate: 44100,
+            bytes_consumed: 0,
+            source: &[],
+            samples: &frame2,
+            sample_count: frame2.len(),
+        };
+        assert!(!resampler.resample(&samples2).is_empty());
+    }
+
+    #[test]
+    fn pcm_queue_consume_exact_spans_chunks() {
+        let mut queue = PcmQueue::new();
+        queue.push([1 as Sample, 2 as Sample, 3 as Sample]);
+        queue.push([4 as Sample, 5 as Sample]);
+
+        let mut out = [0 as Sample; 4];
+        assert!(queue.consume_exact(&mut out));
+        assert_eq!(out, [1 as Sample, 2 as Sample, 3 as Sample, 4 as Sample]);
+        assert_eq!(queue.samples_available(), 1);
+
+        // Not enough buffered for this request: must fail without consuming anything.
+        let mut out = [0 as Sample; 2];
+        assert!(!queue.consume_exact(&mut out));
+        assert_eq!(queue.samples_available(), 1);
+
+        let mut out = [0 as Sample; 1];
+        assert!(queue.consume_exact(&mut out));
+        assert_eq!(out, [5 as Sample]);
+        assert_eq!(queue.samples_available(), 0);
+    }
+
+    #[test]
+    fn seek_index_binary_search_finds_containing_entry() {
+        let per_frame_secs = 1152.0 / 44100.0;
+        let index = SeekIndex {
+            entries: std::vec![
+                IndexEntry {
+                    byte_offset: 0,
+                    sample_rate: 44100,
+                    sample_count: 1152,
+                    samples_before: 0,
+                    duration_before: 0.0,
+                },
+                IndexEntry {
+                    byte_offset: 417,
+                    sample_rate: 44100,
+                    sample_count: 1152,
+                    samples_before: 1152,
+                    duration_before: per_frame_secs,
+                },
+                IndexEntry {
+                    byte_offset: 834,
+                    sample_rate: 44100,
+                    sample_count: 1152,
+                    samples_before: 2304,
+                    duration_before: per_frame_secs * 2.0,
+                },
+            ],
+            indexed_bytes: 1251,
+            done: true,
+        };
+
+        assert_eq!(index.find_by_sample(0).map(|e| e.byte_offset), Some(0));
+        assert_eq!(index.find_by_sample(1151).map(|e| e.byte_offset), Some(0));
+        assert_eq!(index.find_by_sample(1152).map(|e| e.byte_offset), Some(417));
+        assert_eq!(index.find_by_sample(2303).map(|e| e.byte_offset), Some(417));
+        assert_eq!(index.find_by_sample(2304).map(|e| e.byte_offset), Some(834));
+        assert!(index.find_by_sample(3456).is_none());
+
+        assert_eq!(index.find_by_duration(0.0).map(|e| e.byte_offset), Some(0));
+        assert_eq!(
+            index.find_by_duration(per_frame_secs - 1e-9).map(|e| e.byte_offset),
+            Some(0)
+        );
+        assert_eq!(
+            index.find_by_duration(per_frame_secs).map(|e| e.byte_offset),
+            Some(417)
+        );
+        assert!(index.find_by_duration(per_frame_secs * 3.0).is_none());
+    }
+}